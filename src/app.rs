@@ -1,8 +1,20 @@
-use std::process;
+use std::io;
+use std::path::PathBuf;
+use std::process::{self, Stdio};
+use std::sync::Arc;
+use std::{env, fs};
 
 use clap::Parser;
+use nix::sys::signal::{self, Signal};
+use nix::unistd::Pid;
+use tokio::signal::unix::{signal as unix_signal, SignalKind};
+use tokio::sync::broadcast;
 
 use crate::cli::{Cli, Commands};
+use crate::config::{self, Config};
+use crate::interval;
+use crate::scheduler::{self, Scheduler};
+use crate::store::{BackendKind, Store};
 
 #[derive(Debug)]
 enum Status {
@@ -12,7 +24,10 @@ enum Status {
 
 impl Status {
   fn current() -> Self {
-    Self::Running
+    match read_pid() {
+      | Some(pid) if process_alive(pid) => Self::Running,
+      | _ => Self::Stopped,
+    }
   }
 }
 
@@ -56,6 +71,9 @@ pub fn run() {
       }
     },
     | Commands::Parse { expressions } => run_parse_check(expressions),
+    | Commands::Snapshot { path } => run_snapshot(path),
+    | Commands::Dump { path } => run_dump(path),
+    | Commands::Restore { path } => run_restore(path),
   }
 }
 
@@ -64,24 +82,131 @@ fn bail(status: &str) {
   process::exit(1);
 }
 
+fn default_dir() -> PathBuf {
+  let home = env::var("HOME").unwrap_or_else(|_| ".".to_string());
+
+  PathBuf::from(home).join(".supertask")
+}
+
+fn pid_path() -> PathBuf {
+  default_dir().join("supertask.pid")
+}
+
+fn config_path() -> PathBuf {
+  default_dir().join("supertask.toml")
+}
+
+fn store_path() -> PathBuf {
+  default_dir().join("supertask.db")
+}
+
 fn ensure_default_directory() {
-  todo!()
+  if let Err(err) = fs::create_dir_all(default_dir()) {
+    eprintln!("Failed to create supertask directory: {}", err);
+    process::exit(1);
+  }
 }
 
 fn save_pid() {
-  todo!()
+  if let Err(err) = fs::write(pid_path(), process::id().to_string()) {
+    eprintln!("Failed to save pid file: {}", err);
+    process::exit(1);
+  }
+}
+
+fn read_pid() -> Option<i32> {
+  fs::read_to_string(pid_path()).ok()?.trim().parse().ok()
 }
 
+fn remove_pid() {
+  let _ = fs::remove_file(pid_path());
+}
+
+fn process_alive(pid: i32) -> bool {
+  signal::kill(Pid::from_raw(pid), None).is_ok()
+}
+
+/// Runs the scheduler in the foreground, blocking until it receives a shutdown signal.
 fn run_bot() {
-  todo!()
+  let runtime = tokio::runtime::Runtime::new().expect("Failed to start async runtime");
+
+  if let Err(err) = runtime.block_on(run_scheduler()) {
+    eprintln!("Error running supertask: {}", err);
+    remove_pid();
+    process::exit(1);
+  }
+
+  remove_pid();
 }
 
+/// Re-executes this binary as `start`, detached from the current terminal, so the scheduler
+/// keeps running after the invoking shell exits.
 fn run_daemon() {
-  todo!()
+  let exe = match env::current_exe() {
+    | Ok(exe) => exe,
+    | Err(err) => {
+      eprintln!("Failed to resolve supertask executable: {}", err);
+      process::exit(1);
+    },
+  };
+
+  let child = process::Command::new(exe)
+    .arg("start")
+    .stdin(Stdio::null())
+    .stdout(Stdio::null())
+    .stderr(Stdio::null())
+    .spawn();
+
+  if let Err(err) = child {
+    eprintln!("Failed to start supertask daemon: {}", err);
+    process::exit(1);
+  }
 }
 
 fn stop_daemon() {
-  todo!()
+  let Some(pid) = read_pid() else {
+    bail("stopped");
+    return;
+  };
+
+  if let Err(err) = signal::kill(Pid::from_raw(pid), Signal::SIGTERM) {
+    eprintln!("Failed to stop supertask: {}", err);
+    process::exit(1);
+  }
+}
+
+async fn run_scheduler() -> Result<(), scheduler::SchedulerError> {
+  let config = Config::load(&config_path())?;
+  let store = Arc::new(Store::open_with(backend_kind(&config)).await?);
+  let scheduler = Scheduler::new(config, store)?;
+
+  let (shutdown_tx, shutdown_rx) = broadcast::channel(1);
+  tokio::spawn(listen_for_shutdown(shutdown_tx));
+
+  scheduler.run(shutdown_rx).await
+}
+
+/// Resolves the config's selected backend to a [`BackendKind`], rooted at the default store path.
+fn backend_kind(config: &Config) -> BackendKind {
+  match config.backend {
+    | config::Backend::File => BackendKind::File(store_path()),
+    | config::Backend::Sled => BackendKind::Sled(store_path()),
+    #[cfg(feature = "sqlite")]
+    | config::Backend::Sqlite => BackendKind::Sqlite(store_path()),
+  }
+}
+
+/// Resolves once either Ctrl-C or SIGTERM (sent by `supertask stop`) is received.
+async fn listen_for_shutdown(shutdown_tx: broadcast::Sender<()>) {
+  let mut sigterm =
+    unix_signal(SignalKind::terminate()).expect("Failed to install SIGTERM handler");
+
+  tokio::select! {
+    _ = tokio::signal::ctrl_c() => {},
+    _ = sigterm.recv() => {},
+  }
+
+  let _ = shutdown_tx.send(());
 }
 
 fn run_list(_table: bool) {
@@ -92,16 +217,40 @@ fn run_status() {
   todo!()
 }
 
-fn clear(_event: String) {
-  todo!()
+fn clear(event: String) {
+  let runtime = tokio::runtime::Runtime::new().expect("Failed to start async runtime");
+
+  let result = runtime.block_on(async {
+    let config = Config::load(&config_path())?;
+    let store = Store::open_with(backend_kind(&config)).await?;
+
+    scheduler::clear_event(&store, &event).await
+  });
+
+  if let Err(err) = result {
+    eprintln!("Failed to clear '{}': {}", event, err);
+    process::exit(1);
+  }
 }
 
 fn execute(_event: String) {
   todo!()
 }
 
-fn delay(_event: String, _intervals: Vec<String>) {
-  todo!()
+fn delay(event: String, intervals: Vec<String>) {
+  let runtime = tokio::runtime::Runtime::new().expect("Failed to start async runtime");
+
+  let result = runtime.block_on(async {
+    let config = Config::load(&config_path())?;
+    let store = Store::open_with(backend_kind(&config)).await?;
+
+    scheduler::delay_event(&store, &event, &intervals).await
+  });
+
+  if let Err(err) = result {
+    eprintln!("Failed to delay '{}': {}", event, err);
+    process::exit(1);
+  }
 }
 
 fn run_schema() {
@@ -109,9 +258,77 @@ fn run_schema() {
 }
 
 fn get_config_path() -> Result<String, std::io::Error> {
-  Ok("/path/to/config".to_string())
+  Ok(config_path().display().to_string())
 }
 
-fn run_parse_check(_expressions: Vec<String>) {
-  todo!()
+fn run_parse_check(expressions: Vec<String>) {
+  for expression in expressions {
+    match interval::parse(&expression) {
+      | Ok(duration) => println!("{} -> {:?}", expression, duration),
+      | Err(err) => eprintln!("{}: {}", expression, err),
+    }
+  }
+}
+
+fn load_config_or_exit() -> Config {
+  match Config::load(&config_path()) {
+    | Ok(config) => config,
+    | Err(err) => {
+      eprintln!("Failed to load config: {}", err);
+      process::exit(1);
+    },
+  }
+}
+
+fn run_snapshot(path: String) {
+  let config = load_config_or_exit();
+  let runtime = tokio::runtime::Runtime::new().expect("Failed to start async runtime");
+
+  let result: crate::store::Result<()> = runtime.block_on(async {
+    let store = Store::open_with(backend_kind(&config)).await?;
+
+    store.snapshot(&path).await
+  });
+
+  if let Err(err) = result {
+    eprintln!("Failed to snapshot store: {}", err);
+    process::exit(1);
+  }
+}
+
+fn run_dump(path: Option<String>) {
+  let config = load_config_or_exit();
+  let runtime = tokio::runtime::Runtime::new().expect("Failed to start async runtime");
+
+  let result: crate::store::Result<()> = runtime.block_on(async {
+    let store = Store::open_with(backend_kind(&config)).await?;
+
+    match &path {
+      | Some(path) => store.dump(fs::File::create(path)?).await,
+      | None => store.dump(io::stdout()).await,
+    }
+  });
+
+  if let Err(err) = result {
+    eprintln!("Failed to dump store: {}", err);
+    process::exit(1);
+  }
+}
+
+fn run_restore(path: String) {
+  let config = load_config_or_exit();
+  let runtime = tokio::runtime::Runtime::new().expect("Failed to start async runtime");
+
+  let result: crate::store::Result<()> = runtime.block_on(async {
+    let store = Store::open_with(backend_kind(&config)).await?;
+
+    store
+      .restore(io::BufReader::new(fs::File::open(&path)?))
+      .await
+  });
+
+  if let Err(err) = result {
+    eprintln!("Failed to restore store: {}", err);
+    process::exit(1);
+  }
 }
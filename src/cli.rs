@@ -49,4 +49,19 @@ pub(crate) enum Commands {
     /// Interval expressions to parse.
     expressions: Vec<String>,
   },
+  /// Take a consistent on-disk copy of the store.
+  Snapshot {
+    /// Path to write the snapshot to.
+    path: String,
+  },
+  /// Dump the store as newline-delimited JSON.
+  Dump {
+    /// Path to write the dump to. Writes to stdout if omitted.
+    path: Option<String>,
+  },
+  /// Restore the store from a dump produced by `dump`.
+  Restore {
+    /// Path to read the dump from.
+    path: String,
+  },
 }
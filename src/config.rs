@@ -0,0 +1,118 @@
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+  #[error(transparent)]
+  Io(#[from] std::io::Error),
+
+  #[error(transparent)]
+  Toml(#[from] toml::de::Error),
+}
+
+pub type Result<T> = std::result::Result<T, ConfigError>;
+
+/// A single scheduled event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventConfig {
+  /// Unique identifier, referenced by `Clear`, `Run`, `Delay`, etc.
+  pub id: String,
+  /// Shell command to run when the event fires.
+  pub command: String,
+  /// Interval expression (e.g. `5m`, `1h30m`) controlling how often the event fires.
+  pub interval: String,
+}
+
+/// Which [`StoreBackend`](crate::store::StoreBackend) driver the scheduler opens its store
+/// with.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Backend {
+  /// Single bincode-serialized file, rewritten whole on every flush. The default - no extra
+  /// dependencies required.
+  #[default]
+  File,
+  /// Embedded `sled` KV store.
+  Sled,
+  /// Single-table `sqlite` database.
+  #[cfg(feature = "sqlite")]
+  Sqlite,
+}
+
+/// Top-level config file shape.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Config {
+  /// Storage backend for the event store. Defaults to [`Backend::File`].
+  #[serde(default)]
+  pub backend: Backend,
+  #[serde(default)]
+  pub events: Vec<EventConfig>,
+}
+
+impl Config {
+  /// Loads the config from `path`.
+  ///
+  /// If the file does not exist, an empty config (no events) is returned, so a fresh install
+  /// can start the daemon before writing a config file.
+  pub fn load(path: &Path) -> Result<Self> {
+    match fs::read_to_string(path) {
+      | Ok(contents) => Ok(toml::from_str(&contents)?),
+      | Err(ref err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Config::default()),
+      | Err(err) => Err(err.into()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use tempfile::tempdir;
+
+  use super::*;
+
+  #[test]
+  fn test_load_missing_config_is_empty() {
+    let dir = tempdir().unwrap();
+    let config = Config::load(&dir.path().join("missing.toml")).unwrap();
+
+    assert!(config.events.is_empty());
+    assert!(matches!(config.backend, Backend::File));
+  }
+
+  #[test]
+  fn test_load_parses_backend() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("supertask.toml");
+
+    fs::write(&path, r#"backend = "sled""#).unwrap();
+
+    let config = Config::load(&path).unwrap();
+
+    assert!(matches!(config.backend, Backend::Sled));
+  }
+
+  #[test]
+  fn test_load_parses_events() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("supertask.toml");
+
+    fs::write(
+      &path,
+      r#"
+      [[events]]
+      id = "backup"
+      command = "backup.sh"
+      interval = "1h"
+      "#,
+    )
+    .unwrap();
+
+    let config = Config::load(&path).unwrap();
+
+    assert_eq!(config.events.len(), 1);
+    assert_eq!(config.events[0].id, "backup");
+    assert_eq!(config.events[0].interval, "1h");
+  }
+}
@@ -0,0 +1,107 @@
+use std::time::Duration;
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum IntervalError {
+  #[error("Empty interval expression")]
+  Empty,
+
+  #[error("Invalid interval expression '{0}'")]
+  Invalid(String),
+
+  #[error("Unknown interval unit '{0}' in '{1}' (expected one of: s, m, h, d)")]
+  UnknownUnit(char, String),
+}
+
+pub type Result<T> = std::result::Result<T, IntervalError>;
+
+/// Parses an interval expression like `30s`, `5m`, `2h`, `1d`, or a sum of those chained
+/// together (e.g. `1h30m`) into a [`Duration`].
+pub fn parse(expr: &str) -> Result<Duration> {
+  let expr = expr.trim();
+
+  if expr.is_empty() {
+    return Err(IntervalError::Empty);
+  }
+
+  let mut total = Duration::ZERO;
+  let mut digits = String::new();
+
+  for ch in expr.chars() {
+    if ch.is_ascii_digit() {
+      digits.push(ch);
+      continue;
+    }
+
+    if digits.is_empty() {
+      return Err(IntervalError::Invalid(expr.to_string()));
+    }
+
+    let amount: u64 = digits
+      .parse()
+      .map_err(|_| IntervalError::Invalid(expr.to_string()))?;
+
+    digits.clear();
+
+    let unit = match ch {
+      | 's' => Duration::from_secs(amount),
+      | 'm' => Duration::from_secs(amount * 60),
+      | 'h' => Duration::from_secs(amount * 60 * 60),
+      | 'd' => Duration::from_secs(amount * 60 * 60 * 24),
+      | other => return Err(IntervalError::UnknownUnit(other, expr.to_string())),
+    };
+
+    total += unit;
+  }
+
+  if !digits.is_empty() {
+    return Err(IntervalError::Invalid(expr.to_string()));
+  }
+
+  Ok(total)
+}
+
+/// Parses and sums multiple interval expressions, e.g. as given to the `Delay` command.
+pub fn parse_sum(exprs: &[String]) -> Result<Duration> {
+  exprs.iter().try_fold(Duration::ZERO, |total, expr| Ok(total + parse(expr)?))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_single_unit() {
+    assert_eq!(parse("30s").unwrap(), Duration::from_secs(30));
+    assert_eq!(parse("5m").unwrap(), Duration::from_secs(5 * 60));
+    assert_eq!(parse("2h").unwrap(), Duration::from_secs(2 * 60 * 60));
+    assert_eq!(parse("1d").unwrap(), Duration::from_secs(24 * 60 * 60));
+  }
+
+  #[test]
+  fn test_parse_compound_expression() {
+    assert_eq!(parse("1h30m").unwrap(), Duration::from_secs(90 * 60));
+  }
+
+  #[test]
+  fn test_parse_rejects_empty() {
+    assert!(matches!(parse(""), Err(IntervalError::Empty)));
+  }
+
+  #[test]
+  fn test_parse_rejects_unknown_unit() {
+    assert!(matches!(parse("5x"), Err(IntervalError::UnknownUnit('x', _))));
+  }
+
+  #[test]
+  fn test_parse_rejects_missing_unit() {
+    assert!(matches!(parse("30"), Err(IntervalError::Invalid(_))));
+  }
+
+  #[test]
+  fn test_parse_sum() {
+    let exprs = vec!["1h".to_string(), "30m".to_string()];
+    assert_eq!(parse_sum(&exprs).unwrap(), Duration::from_secs(90 * 60));
+  }
+}
@@ -0,0 +1,304 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::{broadcast, Semaphore};
+use tokio::time::Instant;
+
+use crate::config::{Config, EventConfig};
+use crate::interval;
+use crate::store::Store;
+
+/// How many event commands may be running at once.
+const MAX_CONCURRENT_JOBS: usize = 4;
+
+pub type EventId = String;
+
+#[derive(Debug, Error)]
+pub enum SchedulerError {
+  #[error(transparent)]
+  Store(#[from] crate::store::StoreError),
+
+  #[error(transparent)]
+  Interval(#[from] interval::IntervalError),
+
+  #[error(transparent)]
+  Config(#[from] crate::config::ConfigError),
+}
+
+pub type Result<T> = std::result::Result<T, SchedulerError>;
+
+/// Persisted runtime state for a single event, so `List`/`Status` can report it without the
+/// daemon being up.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventState {
+  pub last_run_unix_ms: Option<u64>,
+  pub last_status: Option<i32>,
+  pub error_count: u32,
+}
+
+fn state_key(id: &str) -> String {
+  format!("sched:{id}:state")
+}
+
+fn next_fire_key(id: &str) -> String {
+  format!("sched:{id}:next_fire_unix_ms")
+}
+
+fn unix_ms_now() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .unwrap_or_default()
+    .as_millis() as u64
+}
+
+fn instant_from_unix_ms(unix_ms: u64) -> Instant {
+  let now_ms = unix_ms_now();
+
+  if unix_ms <= now_ms {
+    Instant::now()
+  } else {
+    Instant::now() + Duration::from_millis(unix_ms - now_ms)
+  }
+}
+
+async fn read_next_fire(store: &Store, id: &str) -> Result<Option<u64>> {
+  match store.get::<u64>(next_fire_key(id)).await {
+    | Ok(unix_ms) => Ok(Some(unix_ms)),
+    | Err(crate::store::StoreError::KeyNotFound(_)) => Ok(None),
+    | Err(err) => Err(err.into()),
+  }
+}
+
+async fn write_next_fire(store: &Store, id: &str, unix_ms: u64) -> Result<()> {
+  store.set(next_fire_key(id), unix_ms).await?;
+
+  Ok(())
+}
+
+async fn read_state(store: &Store, id: &str) -> Result<EventState> {
+  match store.get::<EventState>(state_key(id)).await {
+    | Ok(state) => Ok(state),
+    | Err(crate::store::StoreError::KeyNotFound(_)) => Ok(EventState::default()),
+    | Err(err) => Err(err.into()),
+  }
+}
+
+async fn write_state(store: &Store, id: &str, state: &EventState) -> Result<()> {
+  store.set(state_key(id), state).await?;
+
+  Ok(())
+}
+
+/// Pushes `event`'s next-fire time forward by the sum of `intervals`, persisting it so a
+/// running daemon picks up the change on its next check.
+pub async fn delay_event(store: &Store, event: &str, intervals: &[String]) -> Result<()> {
+  let pushback = interval::parse_sum(intervals)?;
+
+  let current = read_next_fire(store, event).await?.unwrap_or_else(unix_ms_now);
+  let pushed_back = current + pushback.as_millis() as u64;
+
+  write_next_fire(store, event, pushed_back).await
+}
+
+/// Resets `event`'s persisted error state and forces it to fire on the daemon's next check.
+pub async fn clear_event(store: &Store, event: &str) -> Result<()> {
+  write_state(store, event, &EventState::default()).await?;
+  write_next_fire(store, event, unix_ms_now()).await
+}
+
+struct Scheduled {
+  id: EventId,
+  command: String,
+  interval: Duration,
+}
+
+/// Background job worker: maintains a min-heap of pending event runs and fires each one as it
+/// comes due, spawning its command and persisting the result into the [`Store`].
+pub struct Scheduler {
+  store: Arc<Store>,
+  events: Vec<Scheduled>,
+}
+
+impl Scheduler {
+  /// Builds a scheduler from `config`, parsing every event's interval expression up front.
+  pub fn new(config: Config, store: Arc<Store>) -> Result<Self> {
+    let events = config
+      .events
+      .into_iter()
+      .map(|EventConfig { id, command, interval }| {
+        Ok(Scheduled {
+          id,
+          command,
+          interval: interval::parse(&interval)?,
+        })
+      })
+      .collect::<Result<Vec<_>>>()?;
+
+    Ok(Scheduler { store, events })
+  }
+
+  /// Runs the worker loop until `shutdown` fires, then waits for any in-flight jobs to drain.
+  pub async fn run(self, mut shutdown: broadcast::Receiver<()>) -> Result<()> {
+    let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_JOBS));
+    let mut in_flight = Vec::new();
+    let mut heap = self.build_heap().await?;
+
+    loop {
+      let Some(Reverse((fire_at, _, _))) = heap.peek() else {
+        let _ = shutdown.recv().await;
+        break;
+      };
+      let fire_at = *fire_at;
+
+      // A long-lived daemon would otherwise accumulate one handle per job run forever.
+      in_flight.retain(|handle: &tokio::task::JoinHandle<()>| !handle.is_finished());
+
+      tokio::select! {
+        _ = tokio::time::sleep_until(fire_at) => {
+          let Some(Reverse((_, queued_unix_ms, id))) = heap.pop() else { continue };
+
+          // `Delay`/`Clear` write straight to the store from a separate invocation, so
+          // re-check before running in case this event's next-fire time moved since we
+          // queued it. Compare the persisted `unix_ms` against the `unix_ms` we queued under,
+          // not re-derived `Instant`s - `instant_from_unix_ms` clamps due timestamps to "now",
+          // which would always compare greater than a `fire_at` slept on to completion.
+          let persisted = self
+            .store
+            .get::<u64>(next_fire_key(&id))
+            .await
+            .unwrap_or_else(|_| unix_ms_now());
+
+          if persisted > queued_unix_ms {
+            heap.push(Reverse((instant_from_unix_ms(persisted), persisted, id)));
+            continue;
+          }
+
+          let Some(scheduled) = self.events.iter().find(|event| event.id == id) else {
+            continue;
+          };
+
+          in_flight.push(self.spawn_run(scheduled, Arc::clone(&semaphore)));
+
+          let next_fire = unix_ms_now() + scheduled.interval.as_millis() as u64;
+          self.store.set(next_fire_key(&id), next_fire).await?;
+          heap.push(Reverse((instant_from_unix_ms(next_fire), next_fire, id)));
+        },
+        _ = shutdown.recv() => break,
+      }
+    }
+
+    for handle in in_flight {
+      let _ = handle.await;
+    }
+
+    Ok(())
+  }
+
+  async fn build_heap(&self) -> Result<BinaryHeap<Reverse<(Instant, u64, EventId)>>> {
+    let mut heap = BinaryHeap::with_capacity(self.events.len());
+
+    for event in &self.events {
+      let next_fire = match read_next_fire(&self.store, &event.id).await? {
+        | Some(unix_ms) => unix_ms,
+        | None => unix_ms_now(),
+      };
+
+      self.store.set(next_fire_key(&event.id), next_fire).await?;
+      heap.push(Reverse((
+        instant_from_unix_ms(next_fire),
+        next_fire,
+        event.id.clone(),
+      )));
+    }
+
+    Ok(heap)
+  }
+
+  fn spawn_run(
+    &self,
+    event: &Scheduled,
+    semaphore: Arc<Semaphore>,
+  ) -> tokio::task::JoinHandle<()> {
+    let store = Arc::clone(&self.store);
+    let id = event.id.clone();
+    let command = event.command.clone();
+
+    tokio::spawn(async move {
+      let Ok(_permit) = semaphore.acquire_owned().await else {
+        return;
+      };
+
+      let status = tokio::process::Command::new("sh")
+        .arg("-c")
+        .arg(&command)
+        .status()
+        .await;
+
+      let mut state = read_state(&store, &id).await.unwrap_or_default();
+      state.last_run_unix_ms = Some(unix_ms_now());
+
+      match status {
+        | Ok(status) => {
+          state.last_status = status.code();
+
+          if status.success() {
+            state.error_count = 0;
+          } else {
+            state.error_count += 1;
+          }
+        },
+        | Err(_) => {
+          state.last_status = None;
+          state.error_count += 1;
+        },
+      }
+
+      let _ = write_state(&store, &id, &state).await;
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use tempfile::tempdir;
+
+  use super::*;
+
+  #[tokio::test]
+  async fn test_due_event_runs() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("db/test.db");
+    let marker = dir.path().join("ran");
+
+    let store = Arc::new(Store::open(db_path.to_str().unwrap()).await.unwrap());
+    let config = Config {
+      backend: Default::default(),
+      events: vec![EventConfig {
+        id: "test-event".to_string(),
+        command: format!("touch {}", marker.display()),
+        interval: "1h".to_string(),
+      }],
+    };
+
+    let scheduler = Scheduler::new(config, Arc::clone(&store)).unwrap();
+    let (shutdown_tx, shutdown_rx) = broadcast::channel(1);
+
+    let handle = tokio::spawn(scheduler.run(shutdown_rx));
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let _ = shutdown_tx.send(());
+    handle
+      .await
+      .expect("Scheduler task panicked")
+      .expect("Scheduler returned an error");
+
+    assert!(marker.exists(), "Due event never ran");
+
+    let state: EventState = store.get(state_key("test-event")).await.unwrap();
+    assert!(state.last_run_unix_ms.is_some());
+  }
+}
@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+use tokio::io::AsyncReadExt;
+
+use super::{Entry, StoreBackend};
+use crate::store::Result;
+
+/// Default backend: a single bincode-serialized `BTreeMap`, rewritten whole on every flush.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FileBackend {
+  data: BTreeMap<String, Vec<u8>>,
+  #[serde(skip)]
+  path: PathBuf,
+}
+
+impl FileBackend {
+  /// Opens the backend at the specified path.
+  ///
+  /// - If the file does not exist, it will be created.
+  /// - If the file exists, it will be loaded.
+  pub async fn open(path: &Path) -> Result<Self> {
+    if let Some(parent) = path.parent() {
+      fs::create_dir_all(parent).await?;
+    }
+
+    match fs::File::open(path).await {
+      | Ok(mut file) => {
+        let mut buffer = Vec::new();
+        file.read_to_end(&mut buffer).await?;
+
+        let mut backend = bincode::deserialize::<FileBackend>(&buffer)?;
+        backend.path = path.to_path_buf();
+
+        Ok(backend)
+      },
+      | Err(ref err) if err.kind() == io::ErrorKind::NotFound => {
+        let backend = FileBackend {
+          data: BTreeMap::new(),
+          path: path.to_path_buf(),
+        };
+
+        backend.save().await?;
+
+        Ok(backend)
+      },
+      | Err(err) => Err(err.into()),
+    }
+  }
+
+  /// Writes the database to a temp file next to `path`, then atomically renames it over the
+  /// target, so a crash mid-write can never leave `path` holding a partial write.
+  async fn save(&self) -> Result<()> {
+    let encoded = bincode::serialize(&self)?;
+    let tmp_path = self.tmp_path();
+
+    fs::write(&tmp_path, &encoded).await?;
+    fs::rename(&tmp_path, &self.path).await?;
+
+    Ok(())
+  }
+
+  fn tmp_path(&self) -> PathBuf {
+    let mut name = self.path.clone().into_os_string();
+    name.push(OsString::from(".tmp"));
+
+    PathBuf::from(name)
+  }
+}
+
+#[async_trait]
+impl StoreBackend for FileBackend {
+  async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+    Ok(self.data.get(key).cloned())
+  }
+
+  async fn set(&mut self, key: &str, value: Vec<u8>) -> Result<()> {
+    self.data.insert(key.to_string(), value);
+
+    Ok(())
+  }
+
+  async fn remove(&mut self, key: &str) -> Result<()> {
+    self.data.remove(key);
+
+    Ok(())
+  }
+
+  fn iter(&self) -> Result<Box<dyn Iterator<Item = Entry> + '_>> {
+    Ok(Box::new(
+      self.data.iter().map(|(key, value)| (key.clone(), value.clone())),
+    ))
+  }
+
+  async fn flush(&mut self) -> Result<()> {
+    self.save().await
+  }
+
+  async fn snapshot(&self, dest: &Path) -> Result<()> {
+    if let Some(parent) = dest.parent() {
+      fs::create_dir_all(parent).await?;
+    }
+
+    fs::copy(&self.path, dest).await?;
+
+    Ok(())
+  }
+}
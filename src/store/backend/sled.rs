@@ -0,0 +1,66 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+
+use super::{Entry, StoreBackend};
+use crate::store::Result;
+
+/// Embedded-KV backend built on [`sled`](https://docs.rs/sled), for stores that outgrow a
+/// whole-file rewrite on every write.
+#[derive(Debug)]
+pub struct SledBackend {
+  db: sled::Db,
+}
+
+impl SledBackend {
+  /// Opens (or creates) the `sled` database at the specified path.
+  pub fn open(path: &Path) -> Result<Self> {
+    let db = sled::open(path)?;
+
+    Ok(SledBackend { db })
+  }
+}
+
+#[async_trait]
+impl StoreBackend for SledBackend {
+  async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+    Ok(self.db.get(key)?.map(|value| value.to_vec()))
+  }
+
+  async fn set(&mut self, key: &str, value: Vec<u8>) -> Result<()> {
+    self.db.insert(key, value)?;
+
+    Ok(())
+  }
+
+  async fn remove(&mut self, key: &str) -> Result<()> {
+    self.db.remove(key)?;
+
+    Ok(())
+  }
+
+  fn iter(&self) -> Result<Box<dyn Iterator<Item = Entry> + '_>> {
+    Ok(Box::new(self.db.iter().filter_map(|entry| {
+      let (key, value) = entry.ok()?;
+      let key = String::from_utf8(key.to_vec()).ok()?;
+
+      Some((key, value.to_vec()))
+    })))
+  }
+
+  async fn flush(&mut self) -> Result<()> {
+    self.db.flush_async().await?;
+
+    Ok(())
+  }
+
+  async fn snapshot(&self, dest: &Path) -> Result<()> {
+    self.db.flush_async().await?;
+
+    let dest_db = sled::open(dest)?;
+    dest_db.import(self.db.export());
+    dest_db.flush_async().await?;
+
+    Ok(())
+  }
+}
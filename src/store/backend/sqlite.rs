@@ -0,0 +1,83 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+use rusqlite::{params, Connection};
+
+use super::{Entry, StoreBackend};
+use crate::store::Result;
+
+/// `sqlite`-backed driver, for users who want a single portable database file with SQL tooling
+/// around it. Stores everything in one table: `kv(key TEXT PRIMARY KEY, value BLOB)`.
+#[derive(Debug)]
+pub struct SqliteBackend {
+  conn: Connection,
+}
+
+impl SqliteBackend {
+  /// Opens (or creates) the `sqlite` database at the specified path.
+  pub fn open(path: &Path) -> Result<Self> {
+    let conn = Connection::open(path)?;
+
+    conn.execute(
+      "CREATE TABLE IF NOT EXISTS kv (key TEXT PRIMARY KEY, value BLOB NOT NULL)",
+      [],
+    )?;
+
+    Ok(SqliteBackend { conn })
+  }
+}
+
+#[async_trait]
+impl StoreBackend for SqliteBackend {
+  async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+    let mut stmt = self.conn.prepare("SELECT value FROM kv WHERE key = ?1")?;
+
+    match stmt.query_row(params![key], |row| row.get::<_, Vec<u8>>(0)) {
+      | Ok(value) => Ok(Some(value)),
+      | Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+      | Err(err) => Err(err.into()),
+    }
+  }
+
+  async fn set(&mut self, key: &str, value: Vec<u8>) -> Result<()> {
+    self.conn.execute(
+      "INSERT INTO kv (key, value) VALUES (?1, ?2)
+       ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+      params![key, value],
+    )?;
+
+    Ok(())
+  }
+
+  async fn remove(&mut self, key: &str) -> Result<()> {
+    self
+      .conn
+      .execute("DELETE FROM kv WHERE key = ?1", params![key])?;
+
+    Ok(())
+  }
+
+  /// Collects every row into memory before returning; rows are not streamed from the cursor.
+  fn iter(&self) -> Result<Box<dyn Iterator<Item = Entry> + '_>> {
+    let mut stmt = self.conn.prepare("SELECT key, value FROM kv")?;
+
+    let entries = stmt
+      .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, Vec<u8>>(1)?)))?
+      .filter_map(|entry| entry.ok())
+      .collect::<Vec<_>>();
+
+    Ok(Box::new(entries.into_iter()))
+  }
+
+  async fn flush(&mut self) -> Result<()> {
+    Ok(())
+  }
+
+  async fn snapshot(&self, dest: &Path) -> Result<()> {
+    self
+      .conn
+      .backup(rusqlite::DatabaseName::Main, dest, None)?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,166 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::backend::StoreBackend;
+use super::{Result, StoreError};
+
+/// Key the current format version is stored under. Reserved: callers may not `set`/`remove` it
+/// directly through [`Store`](super::Store).
+pub(super) const META_KEY: &str = "__meta";
+
+/// Format version this binary understands. Bump it, and register a [`Migration`] from the
+/// previous version, whenever the shape of stored data changes.
+const CURRENT_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Meta {
+  version: u32,
+}
+
+/// A single step that upgrades stored data from one format version to the next.
+#[async_trait]
+pub trait Migration: Send + Sync {
+  /// The version this migration upgrades from.
+  fn from_version(&self) -> u32;
+
+  /// The version this migration upgrades to.
+  fn to_version(&self) -> u32;
+
+  /// Applies the upgrade in place.
+  async fn apply(&self, backend: &mut dyn StoreBackend) -> Result<()>;
+}
+
+/// Stores written before the `__meta` key existed have no recorded version, so `migrate` treats
+/// them as version 0. They used the same `bincode` format `FileBackend` still reads today, so
+/// there is nothing to transform - this migration only exists to carry version 0 forward to
+/// [`CURRENT_VERSION`] instead of leaving such stores unopenable.
+struct V0ToV1;
+
+#[async_trait]
+impl Migration for V0ToV1 {
+  fn from_version(&self) -> u32 {
+    0
+  }
+
+  fn to_version(&self) -> u32 {
+    1
+  }
+
+  async fn apply(&self, _backend: &mut dyn StoreBackend) -> Result<()> {
+    Ok(())
+  }
+}
+
+/// Ordered registry of every migration this binary knows how to run, in ascending
+/// `from_version` order.
+fn registry() -> Vec<Box<dyn Migration>> {
+  vec![Box::new(V0ToV1)]
+}
+
+/// Brings `backend`'s stored data up to [`CURRENT_VERSION`], running every migration step in
+/// order, then persists the reached version.
+///
+/// Fails with [`StoreError::UnsupportedVersion`] if the stored version is newer than this
+/// binary supports, rather than treating the mismatch as corruption.
+pub(crate) async fn migrate(backend: &mut dyn StoreBackend) -> Result<()> {
+  let existing = read_version(backend).await?;
+
+  let mut version = match existing {
+    | Some(version) => version,
+    | None if backend.iter()?.next().is_none() => {
+      // Freshly created store: nothing to migrate, just stamp the current version.
+      return write_version(backend, CURRENT_VERSION).await;
+    },
+    | None => 0,
+  };
+
+  if version > CURRENT_VERSION {
+    return Err(StoreError::UnsupportedVersion {
+      found: version,
+      supported: CURRENT_VERSION,
+    });
+  }
+
+  let migrations = registry();
+
+  while version < CURRENT_VERSION {
+    match migrations.iter().find(|migration| migration.from_version() == version) {
+      | Some(migration) => {
+        migration.apply(backend).await?;
+        version = migration.to_version();
+      },
+      | None => {
+        return Err(StoreError::MissingMigration {
+          from: version,
+          to: CURRENT_VERSION,
+        });
+      },
+    }
+  }
+
+  write_version(backend, version).await
+}
+
+async fn read_version(backend: &dyn StoreBackend) -> Result<Option<u32>> {
+  match backend.get(META_KEY).await? {
+    | Some(bytes) => {
+      let meta: Meta = bincode::deserialize(&bytes)?;
+
+      Ok(Some(meta.version))
+    },
+    | None => Ok(None),
+  }
+}
+
+async fn write_version(backend: &mut dyn StoreBackend, version: u32) -> Result<()> {
+  let encoded = bincode::serialize(&Meta { version })?;
+
+  backend.set(META_KEY, encoded).await?;
+  backend.flush().await
+}
+
+#[cfg(test)]
+mod tests {
+  use tempfile::tempdir;
+
+  use super::*;
+  use crate::store::backend::FileBackend;
+
+  #[tokio::test]
+  async fn test_fresh_store_is_stamped_with_current_version() {
+    let dir = tempdir().unwrap();
+    let mut backend = FileBackend::open(&dir.path().join("test.db")).await.unwrap();
+
+    migrate(&mut backend).await.unwrap();
+
+    assert_eq!(read_version(&backend).await.unwrap(), Some(CURRENT_VERSION));
+  }
+
+  #[tokio::test]
+  async fn test_preexisting_data_without_meta_migrates_to_current_version() {
+    let dir = tempdir().unwrap();
+    let mut backend = FileBackend::open(&dir.path().join("test.db")).await.unwrap();
+
+    // Simulates a store written before the `__meta` key existed: it has data, but no recorded
+    // version. The registered `V0ToV1` migration should carry it forward rather than erroring.
+    backend.set("key1", b"value1".to_vec()).await.unwrap();
+    backend.flush().await.unwrap();
+
+    migrate(&mut backend).await.unwrap();
+
+    assert_eq!(read_version(&backend).await.unwrap(), Some(CURRENT_VERSION));
+    assert_eq!(backend.get("key1").await.unwrap(), Some(b"value1".to_vec()));
+  }
+
+  #[tokio::test]
+  async fn test_newer_version_is_rejected() {
+    let dir = tempdir().unwrap();
+    let mut backend = FileBackend::open(&dir.path().join("test.db")).await.unwrap();
+
+    write_version(&mut backend, CURRENT_VERSION + 1).await.unwrap();
+
+    let result = migrate(&mut backend).await;
+
+    assert!(matches!(result, Err(StoreError::UnsupportedVersion { .. })));
+  }
+}
@@ -0,0 +1,612 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::{broadcast, RwLock};
+
+mod backend;
+mod migration;
+mod transaction;
+mod watch;
+
+pub use backend::{BackendKind, Entry, StoreBackend};
+pub use migration::Migration;
+pub use transaction::Transaction;
+pub use watch::{ChangeEvent, ChangeKind, Subscription};
+
+/// How many unconsumed changes a lagging [`Subscription`] may fall behind by before it starts
+/// skipping events.
+const CHANGE_CHANNEL_CAPACITY: usize = 1024;
+
+#[derive(Debug, Error)]
+pub enum StoreError {
+  #[error(transparent)]
+  Io(#[from] std::io::Error),
+
+  #[error(transparent)]
+  Bincode(#[from] bincode::Error),
+
+  #[error(transparent)]
+  Sled(#[from] sled::Error),
+
+  #[cfg(feature = "sqlite")]
+  #[error(transparent)]
+  Sqlite(#[from] rusqlite::Error),
+
+  #[error(transparent)]
+  Json(#[from] serde_json::Error),
+
+  #[error(transparent)]
+  Base64(#[from] base64::DecodeError),
+
+  #[error("Key '{0}' not found")]
+  KeyNotFound(String),
+
+  #[error("Key '{0}' is reserved for internal use")]
+  KeyReserved(String),
+
+  #[error("Store format version {found} is newer than the {supported} this binary supports")]
+  UnsupportedVersion { found: u32, supported: u32 },
+
+  #[error("No migration registered from version {from} to {to}")]
+  MissingMigration { from: u32, to: u32 },
+}
+
+pub type Result<T> = std::result::Result<T, StoreError>;
+
+#[derive(Debug)]
+pub struct Store {
+  /// Backend driver. Stored in a `tokio::sync::RwLock` so lock acquisition never blocks a
+  /// worker thread.
+  backend: Arc<RwLock<Box<dyn StoreBackend>>>,
+  /// Broadcasts a [`ChangeEvent`] for every `set`/`remove` that commits, so [`Store::subscribe`]
+  /// callers can react without re-reading everything.
+  changes: broadcast::Sender<ChangeEvent>,
+}
+
+impl Store {
+  /// Opens a store at the specified path, using the default file backend.
+  ///
+  /// - If the store does not exist, it will be created.
+  /// - If the store exists, it will be loaded.
+  pub async fn open(path: &str) -> Result<Self> {
+    Self::open_with(BackendKind::File(path.into())).await
+  }
+
+  /// Opens a store using the backend selected by `kind`.
+  ///
+  /// Runs any pending [`Migration`]s before the store becomes available, failing with
+  /// [`StoreError::UnsupportedVersion`] if the stored data is newer than this binary supports.
+  pub async fn open_with(kind: BackendKind) -> Result<Self> {
+    let mut backend = kind.open().await?;
+
+    migration::migrate(backend.as_mut()).await?;
+
+    let (changes, _) = broadcast::channel(CHANGE_CHANNEL_CAPACITY);
+
+    Ok(Store {
+      backend: Arc::new(RwLock::new(backend)),
+      changes,
+    })
+  }
+
+  /// Subscribes to changes for keys starting with `prefix`. Pass `""` to observe every change.
+  pub fn subscribe(&self, prefix: impl Into<String>) -> Subscription {
+    Subscription::new(prefix.into(), self.changes.subscribe())
+  }
+
+  /// Sets a value in the store.
+  ///
+  /// Value is serialized using `bincode` via `serde`, so it must derive or implement serde's
+  /// `Serialize` trait.
+  pub async fn set<T: Serialize>(&self, key: impl AsRef<str>, value: T) -> Result<()> {
+    if key.as_ref() == migration::META_KEY {
+      return Err(StoreError::KeyReserved(key.as_ref().to_string()));
+    }
+
+    let encoded = bincode::serialize(&value)?;
+
+    let mut backend = self.backend.write().await;
+
+    backend.set(key.as_ref(), encoded).await?;
+    backend.flush().await?;
+
+    drop(backend);
+    self.notify(key.as_ref(), ChangeKind::Set);
+
+    Ok(())
+  }
+
+  /// Gets a value from the store.
+  ///
+  /// Value is deserialized using `bincode` via `serde`, so it must derive or implement serde's
+  /// `Deserialize` trait. While constraint is `DeserializeOwned`, it should derive or implement
+  /// `Deserialize` trait instead.
+  pub async fn get<T: DeserializeOwned>(&self, key: impl AsRef<str>) -> Result<T> {
+    let backend = self.backend.read().await;
+
+    match backend.get(key.as_ref()).await? {
+      | Some(bytes) => Ok(bincode::deserialize(&bytes)?),
+      | None => Err(StoreError::KeyNotFound(key.as_ref().to_string())),
+    }
+  }
+
+  /// Removes a value from the store.
+  ///
+  /// If the value does not exist, nothing happens.
+  pub async fn remove(&self, key: impl AsRef<str>) -> Result<()> {
+    if key.as_ref() == migration::META_KEY {
+      return Err(StoreError::KeyReserved(key.as_ref().to_string()));
+    }
+
+    let mut backend = self.backend.write().await;
+
+    backend.remove(key.as_ref()).await?;
+    backend.flush().await?;
+
+    drop(backend);
+    self.notify(key.as_ref(), ChangeKind::Removed);
+
+    Ok(())
+  }
+
+  /// Broadcasts a [`ChangeEvent`] to every current [`Subscription`]. Having no subscribers is
+  /// the common case, so a failed send (no receivers) is not an error.
+  fn notify(&self, key: &str, kind: ChangeKind) {
+    let _ = self.changes.send(ChangeEvent {
+      key: key.to_string(),
+      kind,
+    });
+  }
+
+  /// Collects every key/value pair currently in the store, without deserializing the values.
+  ///
+  /// The `file` and `sled` drivers' [`StoreBackend::iter`] stream their entries (the `sqlite`
+  /// driver collects its rows first), but this still buffers the full result into memory to
+  /// release the backend's read lock before returning. `Store` offers no streaming scan.
+  pub async fn iter(&self) -> Result<Vec<Entry>> {
+    let backend = self.backend.read().await;
+
+    Ok(
+      backend
+        .iter()?
+        .filter(|(key, _)| key != migration::META_KEY)
+        .collect(),
+    )
+  }
+
+  /// Runs `f` as a single, all-or-nothing unit: every `set`/`remove` staged against the
+  /// [`Transaction`] is applied under one held write lock and persisted with exactly one
+  /// `flush`, or - if `f` returns `Err` - nothing is applied at all.
+  pub async fn transaction<F, R>(&self, f: F) -> Result<R>
+  where
+    F: FnOnce(&mut Transaction) -> Result<R>,
+  {
+    let mut txn = Transaction::new();
+    let result = f(&mut txn)?;
+
+    let ops = txn.into_ops();
+    let mut backend = self.backend.write().await;
+
+    for (key, value) in &ops {
+      match value {
+        | Some(value) => backend.set(key, value.clone()).await?,
+        | None => backend.remove(key).await?,
+      }
+    }
+
+    backend.flush().await?;
+
+    drop(backend);
+
+    for (key, value) in &ops {
+      let kind = match value {
+        | Some(_) => ChangeKind::Set,
+        | None => ChangeKind::Removed,
+      };
+
+      self.notify(key, kind);
+    }
+
+    Ok(result)
+  }
+
+  /// Makes a consistent on-disk copy of the store at `dest`, so it can be backed up while the
+  /// daemon keeps running.
+  ///
+  /// Holds the read lock for the duration of the copy so no write can land mid-snapshot.
+  pub async fn snapshot(&self, dest: impl AsRef<Path>) -> Result<()> {
+    let backend = self.backend.read().await;
+
+    backend.snapshot(dest.as_ref()).await
+  }
+
+  /// Dumps every key/value pair as newline-delimited JSON records (`{"key":..,
+  /// "value_b64":..}`), independent of any backend's on-disk layout, so a store can be migrated
+  /// between backends or inspected by hand.
+  pub async fn dump(&self, mut writer: impl std::io::Write) -> Result<()> {
+    for (key, value) in self.iter().await? {
+      let record = DumpRecord {
+        key,
+        value_b64: BASE64.encode(value),
+      };
+
+      writeln!(writer, "{}", serde_json::to_string(&record)?)?;
+    }
+
+    Ok(())
+  }
+
+  /// Restores key/value pairs from a [`Store::dump`] export, writing straight through the
+  /// backend (values are already `bincode`-encoded bytes, so this bypasses `Store::set`'s own
+  /// encoding step).
+  pub async fn restore(&self, reader: impl std::io::BufRead) -> Result<()> {
+    let mut backend = self.backend.write().await;
+
+    for line in reader.lines() {
+      let line = line?;
+
+      if line.is_empty() {
+        continue;
+      }
+
+      let record: DumpRecord = serde_json::from_str(&line)?;
+      let value = BASE64.decode(record.value_b64)?;
+
+      backend.set(&record.key, value).await?;
+    }
+
+    backend.flush().await
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DumpRecord {
+  key: String,
+  value_b64: String,
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+  use std::time::Duration;
+
+  use tempfile::tempdir;
+  use tokio::fs;
+  use tokio::task;
+
+  use super::*;
+
+  async fn create_store(temp_path: &std::path::Path) -> Store {
+    Store::open(temp_path.to_str().unwrap())
+      .await
+      .expect("Failed to create store")
+  }
+
+  #[tokio::test]
+  async fn test_store_initialization() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("db/test.db");
+    let _ = create_store(&db_path).await;
+
+    assert!(db_path.exists());
+  }
+
+  async fn test_store_corruption() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("db/test.db");
+    let store = create_store(&db_path).await;
+
+    store
+      .set("key1", "value1".to_string())
+      .await
+      .expect("Failed to set value");
+
+    fs::remove_file(&db_path)
+      .await
+      .expect("Failed to remove file");
+
+    let result: Result<String> = store.get("key1").await;
+
+    assert!(matches!(result, Err(StoreError::Io(_))));
+  }
+
+  #[tokio::test]
+  async fn test_set_and_get_value() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("db/test.db");
+    let store = create_store(&db_path).await;
+
+    store
+      .set("key1", "value1".to_string())
+      .await
+      .expect("Failed to set value");
+
+    let value: String = store.get("key1").await.expect("Failed to get value");
+
+    assert_eq!(value, "value1");
+  }
+
+  #[tokio::test]
+  async fn test_get_non_existent_key() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("db/test.db");
+    let store = create_store(&db_path).await;
+    let result: Result<String> = store.get("nonexistent_key").await;
+
+    assert!(matches!(result, Err(StoreError::KeyNotFound(_))));
+  }
+
+  #[tokio::test]
+  async fn test_concurrent_access() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("db/test.db");
+    let store = Arc::new(create_store(&db_path).await);
+
+    let handle1 = {
+      let store = Arc::clone(&store);
+
+      task::spawn(async move {
+        store
+          .set("key1", "concurrent_value1".to_string())
+          .await
+          .expect("Failed to set value");
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let value: String = store.get("key1").await.expect("Failed to get value");
+
+        assert_eq!(value, "concurrent_value1");
+      })
+    };
+
+    let handle2 = {
+      let store = Arc::clone(&store);
+
+      task::spawn(async move {
+        store
+          .set("key2", "concurrent_value2".to_string())
+          .await
+          .expect("Failed to set value");
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let value: String = store.get("key2").await.expect("Failed to get value");
+
+        assert_eq!(value, "concurrent_value2");
+      })
+    };
+
+    let _ = tokio::join!(handle1, handle2);
+  }
+
+  #[tokio::test]
+  async fn test_parallel_set_and_get() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("db/test.db");
+    let store = Arc::new(create_store(&db_path).await);
+
+    let handles: Vec<_> = (0..10)
+      .map(|i| {
+        let store = store.clone();
+
+        task::spawn(async move {
+          let key = format!("key{}", i);
+          let value = format!("value{}", i);
+
+          store
+            .set(&key, value.clone())
+            .await
+            .expect("Failed to set value");
+
+          let result: String = store.get(&key).await.expect("Failed to get value");
+
+          assert_eq!(result, value);
+        })
+      })
+      .collect();
+
+    for handle in handles {
+      let _ = handle.await;
+    }
+  }
+
+  #[tokio::test]
+  async fn test_persistent_storage() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("db/test.db");
+
+    {
+      let store = create_store(&db_path).await;
+
+      store
+        .set("key1", "persistent_value".to_string())
+        .await
+        .expect("Failed to set value");
+    }
+
+    let store = create_store(&db_path).await;
+    let value: String = store.get("key1").await.expect("Failed to get value");
+
+    assert_eq!(value, "persistent_value");
+  }
+
+  #[tokio::test]
+  async fn test_snapshot() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("db/test.db");
+    let store = create_store(&db_path).await;
+
+    store
+      .set("key1", "value1".to_string())
+      .await
+      .expect("Failed to set value");
+
+    let snapshot_path = dir.path().join("db/snapshot.db");
+    store
+      .snapshot(&snapshot_path)
+      .await
+      .expect("Failed to snapshot store");
+
+    let snapshot = create_store(&snapshot_path).await;
+    let value: String = snapshot.get("key1").await.expect("Failed to get value");
+
+    assert_eq!(value, "value1");
+  }
+
+  #[tokio::test]
+  async fn test_dump_and_restore() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("db/test.db");
+    let store = create_store(&db_path).await;
+
+    store
+      .set("key1", "value1".to_string())
+      .await
+      .expect("Failed to set value");
+    store
+      .set("key2", "value2".to_string())
+      .await
+      .expect("Failed to set value");
+
+    let mut dump = Vec::new();
+    store.dump(&mut dump).await.expect("Failed to dump store");
+
+    let restored_path = dir.path().join("db/restored.db");
+    let restored = create_store(&restored_path).await;
+    restored
+      .restore(dump.as_slice())
+      .await
+      .expect("Failed to restore store");
+
+    let value: String = restored.get("key1").await.expect("Failed to get value");
+    assert_eq!(value, "value1");
+
+    let value: String = restored.get("key2").await.expect("Failed to get value");
+    assert_eq!(value, "value2");
+  }
+
+  #[tokio::test]
+  async fn test_meta_key_is_reserved() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("db/test.db");
+    let store = create_store(&db_path).await;
+
+    let result = store.set(migration::META_KEY, "sneaky".to_string()).await;
+    assert!(matches!(result, Err(StoreError::KeyReserved(_))));
+
+    let result = store.remove(migration::META_KEY).await;
+    assert!(matches!(result, Err(StoreError::KeyReserved(_))));
+  }
+
+  #[tokio::test]
+  async fn test_transaction_commits_all_ops_together() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("db/test.db");
+    let store = create_store(&db_path).await;
+
+    store
+      .set("key1", "stale".to_string())
+      .await
+      .expect("Failed to set value");
+
+    store
+      .transaction(|txn| {
+        txn.set("key1", "fresh".to_string())?;
+        txn.set("key2", "value2".to_string())?;
+        txn.remove("key1")?;
+
+        Ok(())
+      })
+      .await
+      .expect("Failed to commit transaction");
+
+    let result: Result<String> = store.get("key1").await;
+    assert!(matches!(result, Err(StoreError::KeyNotFound(_))));
+
+    let value: String = store.get("key2").await.expect("Failed to get value");
+    assert_eq!(value, "value2");
+  }
+
+  #[tokio::test]
+  async fn test_transaction_discards_everything_on_error() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("db/test.db");
+    let store = create_store(&db_path).await;
+
+    store
+      .set("key1", "value1".to_string())
+      .await
+      .expect("Failed to set value");
+
+    let result = store
+      .transaction(|txn| {
+        txn.set("key2", "value2".to_string())?;
+        txn.remove("key1")?;
+
+        Err(StoreError::KeyNotFound("boom".to_string()))
+      })
+      .await;
+
+    assert!(matches!(result, Err(StoreError::KeyNotFound(_))));
+
+    let value: String = store.get("key1").await.expect("Failed to get value");
+    assert_eq!(value, "value1");
+
+    let result: Result<String> = store.get("key2").await;
+    assert!(matches!(result, Err(StoreError::KeyNotFound(_))));
+  }
+
+  #[tokio::test]
+  async fn test_subscribe_receives_matching_changes() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("db/test.db");
+    let store = create_store(&db_path).await;
+
+    let mut subscription = store.subscribe("sched:");
+
+    store
+      .set("sched:backup:state", "ran".to_string())
+      .await
+      .expect("Failed to set value");
+
+    let event = subscription.recv().await.expect("Expected a change event");
+
+    assert_eq!(event.key, "sched:backup:state");
+    assert_eq!(event.kind, ChangeKind::Set);
+  }
+
+  #[tokio::test]
+  async fn test_subscribe_filters_by_prefix() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("db/test.db");
+    let store = create_store(&db_path).await;
+
+    let mut subscription = store.subscribe("sched:");
+
+    store
+      .set("other:key", "value".to_string())
+      .await
+      .expect("Failed to set value");
+    store
+      .set("sched:backup:state", "ran".to_string())
+      .await
+      .expect("Failed to set value");
+    store
+      .remove("sched:backup:state")
+      .await
+      .expect("Failed to remove value");
+
+    let event = subscription.recv().await.expect("Expected a change event");
+    assert_eq!(event.key, "sched:backup:state");
+    assert_eq!(event.kind, ChangeKind::Set);
+
+    let event = subscription.recv().await.expect("Expected a change event");
+    assert_eq!(event.key, "sched:backup:state");
+    assert_eq!(event.kind, ChangeKind::Removed);
+  }
+}
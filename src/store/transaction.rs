@@ -0,0 +1,58 @@
+use serde::Serialize;
+
+use super::migration;
+use super::{Result, StoreError};
+
+enum Op {
+  Set(String, Vec<u8>),
+  Remove(String),
+}
+
+/// Buffers `set`/`remove` calls for [`Store::transaction`](super::Store::transaction) so they
+/// can be applied as a single, all-or-nothing unit.
+///
+/// Operations are only staged here; nothing touches the backend until the closure returns
+/// successfully.
+pub struct Transaction {
+  ops: Vec<Op>,
+}
+
+impl Transaction {
+  pub(super) fn new() -> Self {
+    Transaction { ops: Vec::new() }
+  }
+
+  /// Stages setting `key` to `value`. Not visible to readers until the transaction commits.
+  pub fn set<T: Serialize>(&mut self, key: impl AsRef<str>, value: T) -> Result<()> {
+    if key.as_ref() == migration::META_KEY {
+      return Err(StoreError::KeyReserved(key.as_ref().to_string()));
+    }
+
+    let encoded = bincode::serialize(&value)?;
+    self.ops.push(Op::Set(key.as_ref().to_string(), encoded));
+
+    Ok(())
+  }
+
+  /// Stages removing `key`. Not visible to readers until the transaction commits.
+  pub fn remove(&mut self, key: impl AsRef<str>) -> Result<()> {
+    if key.as_ref() == migration::META_KEY {
+      return Err(StoreError::KeyReserved(key.as_ref().to_string()));
+    }
+
+    self.ops.push(Op::Remove(key.as_ref().to_string()));
+
+    Ok(())
+  }
+
+  pub(super) fn into_ops(self) -> Vec<(String, Option<Vec<u8>>)> {
+    self
+      .ops
+      .into_iter()
+      .map(|op| match op {
+        | Op::Set(key, value) => (key, Some(value)),
+        | Op::Remove(key) => (key, None),
+      })
+      .collect()
+  }
+}
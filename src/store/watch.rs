@@ -0,0 +1,44 @@
+use tokio::sync::broadcast;
+
+/// What happened to a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+  Set,
+  Removed,
+}
+
+/// A single committed change to the store, as broadcast to [`Subscription`]s.
+#[derive(Debug, Clone)]
+pub struct ChangeEvent {
+  pub key: String,
+  pub kind: ChangeKind,
+}
+
+/// A [`Store::subscribe`](super::Store::subscribe) handle, filtered to keys starting with a
+/// given prefix.
+pub struct Subscription {
+  prefix: String,
+  receiver: broadcast::Receiver<ChangeEvent>,
+}
+
+impl Subscription {
+  pub(super) fn new(prefix: String, receiver: broadcast::Receiver<ChangeEvent>) -> Self {
+    Subscription { prefix, receiver }
+  }
+
+  /// Waits for the next change whose key starts with this subscription's prefix.
+  ///
+  /// Returns `None` once the store (and every sender clone of it) has been dropped. A slow
+  /// subscriber that falls behind the broadcast channel's buffer silently skips the changes it
+  /// missed rather than erroring, since this is a live-view convenience, not a durable log.
+  pub async fn recv(&mut self) -> Option<ChangeEvent> {
+    loop {
+      match self.receiver.recv().await {
+        | Ok(event) if event.key.starts_with(&self.prefix) => return Some(event),
+        | Ok(_) => continue,
+        | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+        | Err(broadcast::error::RecvError::Closed) => return None,
+      }
+    }
+  }
+}